@@ -47,6 +47,7 @@ use crate::file_fetcher::FileFetcher;
 use crate::file_watcher::ModuleResolutionResult;
 use crate::flags::DenoSubcommand;
 use crate::flags::Flags;
+use crate::flags::UpgradeFlags;
 use crate::fmt_errors::PrettyJsError;
 use crate::import_map::ImportMap;
 use crate::media_type::MediaType;
@@ -57,6 +58,10 @@ use crate::source_maps::apply_source_map;
 use crate::specifier_handler::FetchHandler;
 use crate::standalone::create_standalone_binary;
 use crate::tools::installer::infer_name_from_url;
+use crate::tools::upgrade::ChecksumStatus;
+use crate::tools::upgrade::ListFormat;
+use crate::tools::upgrade::UpgradeAction;
+use crate::tools::upgrade::UpgradeOutcome;
 use deno_core::error::generic_error;
 use deno_core::error::AnyError;
 use deno_core::futures::future::FutureExt;
@@ -1072,6 +1077,171 @@ async fn test_command(
   Ok(())
 }
 
+async fn upgrade_command(upgrade_flags: UpgradeFlags) -> Result<(), AnyError> {
+  let json = upgrade_flags.json;
+  let quiet = upgrade_flags.quiet;
+  // `--check` and quiet dry runs report through the exit code whether an
+  // upgrade is available.
+  let report_exit_code =
+    upgrade_flags.check || (quiet && upgrade_flags.dry_run);
+  let exit_code_on_no_op = upgrade_flags.exit_code_on_no_op;
+  let list_format = ListFormat::from_flag(upgrade_flags.list_format.as_deref());
+  // Failures exit with a code that tells scripts what went wrong, see
+  // `deno upgrade --help`.
+  let outcome = match tools::upgrade::upgrade_command(upgrade_flags, None).await
+  {
+    Ok(outcome) => outcome,
+    Err(err) => {
+      if json {
+        eprintln!("{}", json!({ "error": err.to_string() }));
+      } else {
+        eprintln!("{}: {}", colors::red_bold("error"), err.to_string());
+      }
+      std::process::exit(err.exit_code());
+    }
+  };
+
+  if json {
+    write_json_to_stdout(&outcome)?;
+  } else if !quiet {
+    print_upgrade_outcome(&outcome, list_format);
+  }
+
+  let upgrade_available = matches!(
+    outcome.action,
+    UpgradeAction::UpgradeAvailable | UpgradeAction::DryRun
+  );
+  if report_exit_code && upgrade_available {
+    std::process::exit(tools::upgrade::EXIT_UPGRADE_AVAILABLE);
+  }
+  if exit_code_on_no_op && outcome.action == UpgradeAction::AlreadyLatest {
+    std::process::exit(tools::upgrade::EXIT_NO_OP);
+  }
+  Ok(())
+}
+
+fn print_upgrade_outcome(outcome: &UpgradeOutcome, list_format: ListFormat) {
+  fn paint_stdout<D: std::fmt::Display>(
+    s: &str,
+    color: impl FnOnce(&str) -> D,
+  ) -> String {
+    tools::upgrade::paint(atty::Stream::Stdout, s, color)
+  }
+
+  match outcome.action {
+    UpgradeAction::Upgraded => println!(
+      "{}",
+      paint_stdout(
+        &format!("Upgraded successfully to {}", outcome.new_version),
+        colors::green
+      )
+    ),
+    UpgradeAction::Downgraded => println!(
+      "{}",
+      paint_stdout(
+        &format!("Downgraded successfully to {}", outcome.new_version),
+        colors::green
+      )
+    ),
+    UpgradeAction::AlreadyLatest => println!(
+      "{}",
+      paint_stdout(
+        &format!(
+          "Local deno version {} is up to date",
+          outcome.previous_version
+        ),
+        colors::green
+      )
+    ),
+    UpgradeAction::DryRun => {
+      println!(
+        "Dry run finished, {} was not installed",
+        outcome.new_version
+      );
+      println!(
+        "  Version:       {} -> {}",
+        outcome.previous_version, outcome.new_version
+      );
+      if let Some(download_size) = outcome.download_size {
+        println!(
+          "  Download size: {}",
+          info::human_size(download_size as f64)
+        );
+      }
+      match outcome.checksum {
+        Some(ChecksumStatus::Verified) => println!(
+          "  Checksum:      {}",
+          paint_stdout("verified", colors::green)
+        ),
+        Some(ChecksumStatus::NotPublished) => println!(
+          "  Checksum:      {}",
+          paint_stdout("not published, not verified", colors::yellow)
+        ),
+        None => {}
+      }
+      if let Some(staged_path) = &outcome.staged_path {
+        println!("  Staged at:     {}", staged_path.display());
+      }
+      if outcome.path.exists() {
+        println!("  Would replace {}", outcome.path.display());
+      } else {
+        println!("  Would install to {}", outcome.path.display());
+      }
+    }
+    UpgradeAction::UpgradeAvailable => println!(
+      "{}",
+      paint_stdout(
+        &format!(
+          "A new version of deno is available: {} (current: {})",
+          outcome.new_version, outcome.previous_version
+        ),
+        colors::yellow
+      )
+    ),
+    UpgradeAction::Cancelled => {
+      println!("{}", paint_stdout("Upgrade cancelled", colors::yellow))
+    }
+    UpgradeAction::RolledBack => println!(
+      "{}",
+      paint_stdout(
+        &format!("Rolled back to {}", outcome.new_version),
+        colors::green
+      )
+    ),
+    UpgradeAction::Listed => print!(
+      "{}",
+      tools::upgrade::render_releases(
+        &outcome.releases,
+        &outcome.previous_version,
+        list_format
+      )
+    ),
+    UpgradeAction::Planned => {
+      println!("{} -> {}", outcome.previous_version, outcome.new_version)
+    }
+    UpgradeAction::TargetPrinted => {
+      println!("Version:      {}", outcome.new_version);
+      if let Some(target) = &outcome.target {
+        println!("Target:       {}", target);
+      }
+      if let Some(archive_name) = &outcome.archive_name {
+        println!("Archive:      {}", archive_name);
+      }
+      if let Some(download_url) = &outcome.download_url {
+        println!("Download URL: {}", download_url);
+      }
+    }
+    UpgradeAction::UrlPrinted => {
+      if let Some(download_url) = &outcome.download_url {
+        println!("{}", download_url);
+      }
+    }
+  }
+  if let Some(backup_path) = &outcome.backup_path {
+    println!("Previous version saved to {}", backup_path.display());
+  }
+}
+
 fn init_v8_flags(v8_flags: &[String]) {
   let v8_flags_includes_help = v8_flags
     .iter()
@@ -1096,38 +1266,44 @@ fn init_v8_flags(v8_flags: &[String]) {
   }
 }
 
-fn init_logger(maybe_level: Option<Level>) {
+/// Sets up logging at `maybe_level`. Debug logs of `verbose_module` are
+/// enabled regardless of the level.
+fn init_logger(maybe_level: Option<Level>, verbose_module: Option<&str>) {
   let log_level = match maybe_level {
     Some(level) => level,
     None => Level::Info, // Default log level
   };
-  env_logger::Builder::from_env(
+  let mut builder = env_logger::Builder::from_env(
     env_logger::Env::default()
       .default_filter_or(log_level.to_level_filter().to_string()),
-  )
+  );
   // https://github.com/denoland/deno/issues/6641
-  .filter_module("rustyline", LevelFilter::Off)
-  .format(|buf, record| {
-    let mut target = record.target().to_string();
-    if let Some(line_no) = record.line() {
-      target.push(':');
-      target.push_str(&line_no.to_string());
-    }
-    if record.level() <= Level::Info {
-      // Print ERROR, WARN, INFO logs as they are
-      writeln!(buf, "{}", record.args())
-    } else {
-      // Add prefix to DEBUG or TRACE logs
-      writeln!(
-        buf,
-        "{} RS - {} - {}",
-        record.level(),
-        target,
-        record.args()
-      )
-    }
-  })
-  .init();
+  builder.filter_module("rustyline", LevelFilter::Off);
+  if let Some(module) = verbose_module {
+    builder.filter_module(module, LevelFilter::Debug);
+  }
+  builder
+    .format(|buf, record| {
+      let mut target = record.target().to_string();
+      if let Some(line_no) = record.line() {
+        target.push(':');
+        target.push_str(&line_no.to_string());
+      }
+      if record.level() <= Level::Info {
+        // Print ERROR, WARN, INFO logs as they are
+        writeln!(buf, "{}", record.args())
+      } else {
+        // Add prefix to DEBUG or TRACE logs
+        writeln!(
+          buf,
+          "{} RS - {} - {}",
+          record.level(),
+          target,
+          record.args()
+        )
+      }
+    })
+    .init();
 }
 
 fn get_subcommand(
@@ -1209,17 +1385,9 @@ fn get_subcommand(
       }
       std::process::exit(0);
     }
-    DenoSubcommand::Upgrade {
-      force,
-      dry_run,
-      canary,
-      version,
-      output,
-      ca_file,
-    } => tools::upgrade::upgrade_command(
-      dry_run, force, canary, version, output, ca_file,
-    )
-    .boxed_local(),
+    DenoSubcommand::Upgrade(upgrade_flags) => {
+      upgrade_command(upgrade_flags).boxed_local()
+    }
   }
 }
 
@@ -1237,7 +1405,15 @@ pub fn main() {
   if !flags.v8_flags.is_empty() {
     init_v8_flags(&*flags.v8_flags);
   }
-  init_logger(flags.log_level);
+  // `deno upgrade --verbose` logs each step of the upgrade, without the debug
+  // output of the HTTP stack.
+  let verbose_module = match &flags.subcommand {
+    DenoSubcommand::Upgrade(upgrade_flags) if upgrade_flags.verbose => {
+      Some("deno::tools::upgrade")
+    }
+    _ => None,
+  };
+  init_logger(flags.log_level, verbose_module);
 
   let subcommand_future = get_subcommand(flags);
   let result = tokio_util::run_basic(subcommand_future);
@@ -664,6 +664,7 @@ fn upgrade_with_version_in_tmpdir() {
   let status = Command::new(&exe_path)
     .arg("upgrade")
     .arg("--force")
+    .arg("--yes")
     .arg("--version")
     .arg("0.42.0")
     .spawn()
@@ -693,6 +694,7 @@ fn upgrade_with_canary_in_tmpdir() {
   let status = Command::new(&exe_path)
     .arg("upgrade")
     .arg("--canary")
+    .arg("--unstable")
     .arg("--version")
     .arg("e6685f0f01b8a11a5eaff020f5babcfde76b3038")
     .spawn()